@@ -1,13 +1,37 @@
-use specs::{Component, DenseVecStorage, FlaggedStorage};
+use specs::{Component, DenseVecStorage, Entity, FlaggedStorage};
 
 use crate::{
     nalgebra::{Isometry3, Matrix3, Point3, RealField, Vector3},
+    ncollide::shape::{Ball, Capsule, Cuboid, Cylinder, ShapeHandle, TriMesh},
     nphysics::{
         algebra::{Force3, ForceType, Velocity3},
-        object::{Body, BodyPart, BodyStatus, DefaultBodyHandle, RigidBody, RigidBodyDesc},
+        object::{
+            Body, BodyPart, BodyPartHandle, BodyStatus, ColliderDesc, CollisionGroups,
+            DefaultBodyHandle, DefaultBodySet, DefaultColliderHandle, DefaultColliderSet, RigidBody,
+            RigidBodyDesc, UserData,
+        },
     },
 };
 
+/// A single pending force/impulse/velocity change that has been queued up via
+/// [`PhysicsBody::apply_external_force`] and its sibling methods, waiting to
+/// be applied to the nphysics `RigidBody` on the next `apply_to_physics_world`
+/// call.
+///
+/// `local_application_point`, when set, is expressed in the body's local
+/// frame (e.g. the offset of a box corner from its centre of mass); only
+/// `force.linear` is applied at that point; any `force.angular` queued
+/// alongside it is dropped, since nphysics' point-application setters take a
+/// linear force vector.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ExternalForce<N: RealField> {
+    force: Force3<N>,
+    force_type: ForceType,
+    part_index: usize,
+    auto_wake_up: bool,
+    local_application_point: Option<Point3<N>>,
+}
+
 pub mod util {
     use specs::{Component, DenseVecStorage, FlaggedStorage};
 
@@ -57,7 +81,7 @@ pub trait Position<N: RealField>:
 /// The `PhysicsBody` `Component` represents a `PhysicsWorld` `RigidBody` in
 /// Specs and contains all the data required for the synchronisation between
 /// both worlds.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct PhysicsBody<N: RealField> {
     pub(crate) handle: Option<DefaultBodyHandle>,
     pub gravity_enabled: bool,
@@ -67,7 +91,9 @@ pub struct PhysicsBody<N: RealField> {
     pub mass: N,
     pub local_center_of_mass: Point3<N>,
     pub rotations_kinematic: Vector3<bool>,
-    external_forces: Force3<N>,
+    pub translations_kinematic: Vector3<bool>,
+    pub target_isometry: Option<Isometry3<N>>,
+    external_forces: Vec<ExternalForce<N>>,
 }
 
 impl<N: RealField> Component for PhysicsBody<N> {
@@ -75,17 +101,95 @@ impl<N: RealField> Component for PhysicsBody<N> {
 }
 
 impl<N: RealField> PhysicsBody<N> {
-    pub fn check_external_force(&self) -> &Force3<N> {
-        &self.external_forces
+    pub fn check_external_force(&self) -> impl Iterator<Item = &Force3<N>> {
+        self.external_forces.iter().map(|entry| &entry.force)
     }
 
+    /// Queues up a continuous `Force3` to be applied to body part `0` on the
+    /// next `apply_to_physics_world` call.
     pub fn apply_external_force(&mut self, force: &Force3<N>) -> &mut Self {
-        self.external_forces += *force;
+        self.queue_force(*force, ForceType::Force, 0, true, None)
+    }
+
+    /// Queues up an instantaneous impulse to be applied to body part `0`.
+    pub fn apply_impulse(&mut self, impulse: &Force3<N>) -> &mut Self {
+        self.queue_force(*impulse, ForceType::Impulse, 0, true, None)
+    }
+
+    /// Queues up a pure torque impulse, ignoring the linear part of `torque`,
+    /// to be applied to body part `0`.
+    pub fn apply_torque(&mut self, torque: &Force3<N>) -> &mut Self {
+        self.queue_force(
+            Force3::torque(torque.angular),
+            ForceType::Impulse,
+            0,
+            true,
+            None,
+        )
+    }
+
+    /// Queues up a force to be applied at a given body-local application
+    /// point of `part_index`, rather than at the body's centre of mass. Only
+    /// `force.linear` is applied at `point`; `force.angular` is ignored.
+    pub fn apply_force_at_point(
+        &mut self,
+        force: &Force3<N>,
+        point: Point3<N>,
+        part_index: usize,
+    ) -> &mut Self {
+        self.queue_force(*force, ForceType::Force, part_index, true, Some(point))
+    }
+
+    /// Queues up an instantaneous change in velocity (ignoring the body's
+    /// mass) to be applied to body part `0`.
+    pub fn apply_velocity_change(&mut self, velocity_change: &Force3<N>) -> &mut Self {
+        self.queue_force(*velocity_change, ForceType::VelocityChange, 0, true, None)
+    }
+
+    /// Queues up an instantaneous change in acceleration (ignoring the body's
+    /// mass) to be applied to body part `0`.
+    pub fn apply_acceleration_change(&mut self, acceleration_change: &Force3<N>) -> &mut Self {
+        self.queue_force(
+            *acceleration_change,
+            ForceType::AccelerationChange,
+            0,
+            true,
+            None,
+        )
+    }
+
+    /// Sets the target world pose for a `BodyStatus::Kinematic` body. The
+    /// velocity needed to reach it over the current timestep is computed on
+    /// the next `apply_to_physics_world` call.
+    pub fn move_to(&mut self, isometry: Isometry3<N>) -> &mut Self {
+        self.target_isometry = Some(isometry);
+        self
+    }
+
+    fn queue_force(
+        &mut self,
+        force: Force3<N>,
+        force_type: ForceType,
+        part_index: usize,
+        auto_wake_up: bool,
+        local_application_point: Option<Point3<N>>,
+    ) -> &mut Self {
+        self.external_forces.push(ExternalForce {
+            force,
+            force_type,
+            part_index,
+            auto_wake_up,
+            local_application_point,
+        });
         self
     }
 
     /// For creating new rigid body from this component's values
-    pub(crate) fn to_rigid_body_desc(&self) -> RigidBodyDesc<N> {
+    ///
+    /// `entity` is stamped onto the `RigidBodyDesc` as nphysics `user_data` so
+    /// that contact/proximity events reported against the resulting body can
+    /// be mapped back to the owning Specs `Entity` via [`body_entity`].
+    pub(crate) fn to_rigid_body_desc(&self, entity: Entity) -> RigidBodyDesc<N> {
         RigidBodyDesc::new()
             .gravity_enabled(self.gravity_enabled)
             .status(self.body_status)
@@ -93,18 +197,51 @@ impl<N: RealField> PhysicsBody<N> {
             .angular_inertia(self.angular_inertia)
             .mass(self.mass)
             .local_center_of_mass(self.local_center_of_mass)
+            .user_data(entity)
     }
 
-    /// Note: applies forces by draining external force property
-    pub(crate) fn apply_to_physics_world(&mut self, rigid_body: &mut RigidBody<N>) -> &mut Self {
+    /// Note: applies forces by draining the queued external force entries
+    ///
+    /// `dt` is the duration of the current time step and `position` is the
+    /// body's current `Position` isometry; both are required to turn a
+    /// queued [`PhysicsBody::target_isometry`] into the velocity needed to
+    /// reach it over the step.
+    pub(crate) fn apply_to_physics_world(
+        &mut self,
+        rigid_body: &mut RigidBody<N>,
+        dt: N,
+        position: &Isometry3<N>,
+    ) -> &mut Self {
         rigid_body.enable_gravity(self.gravity_enabled);
         rigid_body.set_status(self.body_status);
+        if self.body_status == BodyStatus::Kinematic {
+            if let Some(target_isometry) = self.target_isometry {
+                self.velocity = Velocity3::between_positions(position, &target_isometry, dt);
+            }
+        }
         rigid_body.set_velocity(self.velocity);
         rigid_body.set_angular_inertia(self.angular_inertia);
         rigid_body.set_mass(self.mass);
         rigid_body.set_local_center_of_mass(self.local_center_of_mass);
-        rigid_body.apply_force(0, &self.drain_external_force(), ForceType::Force, true);
+        for entry in self.drain_external_forces() {
+            match entry.local_application_point {
+                Some(point) => rigid_body.apply_force_at_local_point(
+                    entry.part_index,
+                    &entry.force.linear,
+                    &point,
+                    entry.force_type,
+                    entry.auto_wake_up,
+                ),
+                None => rigid_body.apply_force(
+                    entry.part_index,
+                    &entry.force,
+                    entry.force_type,
+                    entry.auto_wake_up,
+                ),
+            }
+        }
         rigid_body.set_rotations_kinematic(self.rotations_kinematic);
+        rigid_body.set_translations_kinematic(self.translations_kinematic);
         self
     }
 
@@ -121,10 +258,236 @@ impl<N: RealField> PhysicsBody<N> {
         self
     }
 
-    fn drain_external_force(&mut self) -> Force3<N> {
-        let value = self.external_forces;
-        self.external_forces = Force3::<N>::zero();
-        value
+    fn drain_external_forces(&mut self) -> Vec<ExternalForce<N>> {
+        std::mem::take(&mut self.external_forces)
+    }
+}
+
+/// Retrieves the Specs `Entity` that owns a given `RigidBody`, as previously
+/// stamped on it via [`PhysicsBody::to_rigid_body_desc`]. Returns `None` if
+/// the body carries no user data or the user data isn't an `Entity`, which is
+/// the case for any body that wasn't created through this crate.
+pub fn body_entity<N: RealField>(rigid_body: &RigidBody<N>) -> Option<Entity> {
+    rigid_body
+        .user_data()
+        .and_then(|data| data.as_any().downcast_ref::<Entity>())
+        .copied()
+}
+
+/// A dense, cache-friendly mirror of the `PhysicsBody` data touched every
+/// step by `apply_to_physics_world`/`update_from_physics_world`, keyed by a
+/// slot index instead of the sparse `FlaggedStorage` lookups the per-entity
+/// sync normally goes through.
+///
+/// `PhysicsBody` stays the public component API; this buffer is an optional,
+/// internal mirror that the sync systems rebuild from the component's change
+/// events (inserted/modified/removed) and use to iterate contiguous arrays
+/// in bulk for scenes with large numbers of bodies.
+#[derive(Default)]
+pub(crate) struct PhysicsBodySoaBuffer<N: RealField> {
+    entities: Vec<Entity>,
+    handles: Vec<DefaultBodyHandle>,
+    positions: Vec<Isometry3<N>>,
+    velocities: Vec<Velocity3<N>>,
+    angular_inertias: Vec<Matrix3<N>>,
+    masses: Vec<N>,
+    slots: std::collections::HashMap<Entity, usize>,
+}
+
+impl<N: RealField> PhysicsBodySoaBuffer<N> {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Inserts a new slot for `entity`, or overwrites its existing one if it
+    /// is already tracked.
+    pub(crate) fn upsert(
+        &mut self,
+        entity: Entity,
+        handle: DefaultBodyHandle,
+        position: Isometry3<N>,
+        velocity: Velocity3<N>,
+        angular_inertia: Matrix3<N>,
+        mass: N,
+    ) {
+        if let Some(&slot) = self.slots.get(&entity) {
+            self.handles[slot] = handle;
+            self.positions[slot] = position;
+            self.velocities[slot] = velocity;
+            self.angular_inertias[slot] = angular_inertia;
+            self.masses[slot] = mass;
+        } else {
+            let slot = self.entities.len();
+            self.entities.push(entity);
+            self.handles.push(handle);
+            self.positions.push(position);
+            self.velocities.push(velocity);
+            self.angular_inertias.push(angular_inertia);
+            self.masses.push(mass);
+            self.slots.insert(entity, slot);
+        }
+    }
+
+    /// Reads back the mirrored position/velocity/angular_inertia/mass for
+    /// `entity`'s slot, if it is tracked. Used to scatter a bulk-synced value
+    /// onto a single `PhysicsBody` once [`pull_physics_world`] has refreshed
+    /// the buffer.
+    pub(crate) fn get(
+        &self,
+        entity: Entity,
+    ) -> Option<(&Isometry3<N>, &Velocity3<N>, &Matrix3<N>, &N)> {
+        let &slot = self.slots.get(&entity)?;
+        Some((
+            &self.positions[slot],
+            &self.velocities[slot],
+            &self.angular_inertias[slot],
+            &self.masses[slot],
+        ))
+    }
+
+    /// Removes the slot for `entity`, if any, swap-removing to keep the
+    /// backing `Vec`s dense and re-indexing whichever slot was moved into its
+    /// place. Called by the body-removal path when an entity's `PhysicsBody`
+    /// is deleted, the counterpart to [`PhysicsBodySoaBuffer::upsert`] on the
+    /// registration side.
+    pub(crate) fn remove(&mut self, entity: Entity) {
+        let slot = match self.slots.remove(&entity) {
+            Some(slot) => slot,
+            None => return,
+        };
+        self.entities.swap_remove(slot);
+        self.handles.swap_remove(slot);
+        self.positions.swap_remove(slot);
+        self.velocities.swap_remove(slot);
+        self.angular_inertias.swap_remove(slot);
+        self.masses.swap_remove(slot);
+        if let Some(&moved_entity) = self.entities.get(slot) {
+            self.slots.insert(moved_entity, slot);
+        }
+    }
+
+    /// Iterates the dense arrays for the `apply_to_physics_world` bulk pass,
+    /// yielding each slot's entity, body handle, and mutable access to its
+    /// mirrored position/velocity/angular_inertia/mass.
+    pub(crate) fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<
+        Item = (
+            Entity,
+            DefaultBodyHandle,
+            &mut Isometry3<N>,
+            &mut Velocity3<N>,
+            &mut Matrix3<N>,
+            &mut N,
+        ),
+    > {
+        let entities = self.entities.iter().copied();
+        let handles = self.handles.iter().copied();
+        let positions = self.positions.iter_mut();
+        let velocities = self.velocities.iter_mut();
+        let angular_inertias = self.angular_inertias.iter_mut();
+        let masses = self.masses.iter_mut();
+        entities
+            .zip(handles)
+            .zip(positions)
+            .zip(velocities)
+            .zip(angular_inertias)
+            .zip(masses)
+            .map(
+                |(((((entity, handle), position), velocity), angular_inertia), mass)| {
+                    (entity, handle, position, velocity, angular_inertia, mass)
+                },
+            )
+    }
+}
+
+/// Bulk counterpart to [`PhysicsBody::apply_to_physics_world`] for large
+/// scenes: computes each kinematic body's target-pose velocity and stages
+/// the position/velocity/angular_inertia/mass of every body that has a
+/// handle into `buffer` (bodies without one are skipped), ready for
+/// [`flush_physics_world`] to write the whole buffer back to nphysics in one
+/// contiguous pass over the buffer.
+pub(crate) fn apply_to_physics_world_bulk<'a, N: RealField>(
+    buffer: &mut PhysicsBodySoaBuffer<N>,
+    bodies: impl IntoIterator<Item = (Entity, &'a mut PhysicsBody<N>, &'a Isometry3<N>)>,
+    dt: N,
+) {
+    for (entity, body, position) in bodies {
+        if body.body_status == BodyStatus::Kinematic {
+            if let Some(target_isometry) = body.target_isometry {
+                body.velocity = Velocity3::between_positions(position, &target_isometry, dt);
+            }
+        }
+        if let Some(handle) = body.handle {
+            buffer.upsert(
+                entity,
+                handle,
+                *position,
+                body.velocity,
+                body.angular_inertia,
+                body.mass,
+            );
+        }
+    }
+}
+
+/// Writes `buffer`'s mirrored velocity/angular_inertia/mass back to nphysics
+/// in one dense pass, looking up each slot's `RigidBody` in `bodies` directly
+/// rather than through a caller-supplied closure, since a closure cannot
+/// hand back a `&mut RigidBody` borrowed from `bodies` on every call without
+/// tying all of them to the same lifetime.
+pub(crate) fn flush_physics_world<N: RealField>(
+    buffer: &mut PhysicsBodySoaBuffer<N>,
+    bodies: &mut DefaultBodySet<N>,
+) {
+    for (_, handle, _, velocity, angular_inertia, mass) in buffer.iter_mut() {
+        if let Some(rigid_body) = bodies.rigid_body_mut(handle) {
+            rigid_body.set_velocity(*velocity);
+            rigid_body.set_angular_inertia(*angular_inertia);
+            rigid_body.set_mass(*mass);
+        }
+    }
+}
+
+/// Bulk counterpart to [`PhysicsBody::update_from_physics_world`]: pulls
+/// position/velocity/angular_inertia/mass for every tracked slot from
+/// nphysics into `buffer` in one dense pass, looking up each slot's
+/// `RigidBody` in `bodies` directly for the same reason as
+/// [`flush_physics_world`].
+pub(crate) fn pull_physics_world<N: RealField>(
+    buffer: &mut PhysicsBodySoaBuffer<N>,
+    bodies: &DefaultBodySet<N>,
+) {
+    for (_, handle, position, velocity, angular_inertia, mass) in buffer.iter_mut() {
+        if let Some(rigid_body) = bodies.rigid_body(handle) {
+            *position = *rigid_body.position();
+            *velocity = *rigid_body.velocity();
+            let local_inertia = rigid_body.local_inertia();
+            *angular_inertia = local_inertia.angular;
+            *mass = local_inertia.linear;
+        }
+    }
+}
+
+/// Scatters `buffer`'s mirrored position/velocity/angular_inertia/mass for
+/// `entity` back onto `body`, completing the bulk
+/// [`PhysicsBody::update_from_physics_world`] pass after
+/// [`pull_physics_world`] has refreshed the buffer.
+pub(crate) fn update_from_physics_world_bulk<N: RealField>(
+    body: &mut PhysicsBody<N>,
+    entity: Entity,
+    position: &mut Isometry3<N>,
+    buffer: &PhysicsBodySoaBuffer<N>,
+) {
+    if let Some((buffered_position, velocity, angular_inertia, mass)) = buffer.get(entity) {
+        *position = *buffered_position;
+        body.velocity = *velocity;
+        body.angular_inertia = *angular_inertia;
+        body.mass = *mass;
     }
 }
 
@@ -157,6 +520,8 @@ pub struct PhysicsBodyBuilder<N: RealField> {
     mass: N,
     local_center_of_mass: Point3<N>,
     rotations_kinematic: Vector3<bool>,
+    translations_kinematic: Vector3<bool>,
+    target_isometry: Option<Isometry3<N>>,
 }
 
 impl<N: RealField> From<BodyStatus> for PhysicsBodyBuilder<N> {
@@ -171,6 +536,8 @@ impl<N: RealField> From<BodyStatus> for PhysicsBodyBuilder<N> {
             mass: N::from_f32(1.2).unwrap(),
             local_center_of_mass: Point3::origin(),
             rotations_kinematic: Vector3::new(false, false, false),
+            translations_kinematic: Vector3::new(false, false, false),
+            target_isometry: None,
         }
     }
 }
@@ -216,6 +583,25 @@ impl<N: RealField> PhysicsBodyBuilder<N> {
         self
     }
 
+    pub fn translations_kinematic(mut self, translations_kinematic: Vector3<bool>) -> Self {
+        self.translations_kinematic = translations_kinematic;
+        self
+    }
+
+    pub fn lock_translations(mut self, lock_translations: bool) -> Self {
+        self.translations_kinematic =
+            Vector3::new(lock_translations, lock_translations, lock_translations);
+        self
+    }
+
+    /// Sets a target world pose for a `BodyStatus::Kinematic` body. The sync
+    /// system will drive the body's velocity towards this pose over the
+    /// current timestep rather than requiring it to be computed by hand.
+    pub fn move_to(mut self, isometry: Isometry3<N>) -> Self {
+        self.target_isometry = Some(isometry);
+        self
+    }
+
     /// Builds the `PhysicsBody` from the values set in the `PhysicsBodyBuilder`
     /// instance.
     pub fn build(self) -> PhysicsBody<N> {
@@ -227,8 +613,185 @@ impl<N: RealField> PhysicsBodyBuilder<N> {
             angular_inertia: self.angular_inertia,
             mass: self.mass,
             local_center_of_mass: self.local_center_of_mass,
-            external_forces: Force3::zero(),
+            external_forces: Vec::new(),
             rotations_kinematic: self.rotations_kinematic,
+            translations_kinematic: self.translations_kinematic,
+            target_isometry: self.target_isometry,
+        }
+    }
+}
+
+/// A typed description of an ncollide collision shape, used by
+/// [`PhysicsCollider`] to build the `ShapeHandle` handed to nphysics without
+/// requiring users to hand-assemble ncollide shapes themselves.
+#[derive(Clone, Debug)]
+pub enum Geometry<N: RealField> {
+    Box {
+        half_extents: Vector3<N>,
+    },
+    Sphere {
+        radius: N,
+    },
+    Capsule {
+        half_height: N,
+        radius: N,
+    },
+    Cylinder {
+        half_height: N,
+        radius: N,
+    },
+    Mesh {
+        points: Vec<Point3<N>>,
+        indices: Vec<Point3<usize>>,
+    },
+}
+
+impl<N: RealField> Geometry<N> {
+    /// Converts this `Geometry` into the ncollide `ShapeHandle` required by
+    /// `ColliderDesc`.
+    pub(crate) fn to_shape_handle(&self) -> ShapeHandle<N> {
+        match self {
+            Geometry::Box { half_extents } => ShapeHandle::new(Cuboid::new(*half_extents)),
+            Geometry::Sphere { radius } => ShapeHandle::new(Ball::new(*radius)),
+            Geometry::Capsule {
+                half_height,
+                radius,
+            } => ShapeHandle::new(Capsule::new(*half_height, *radius)),
+            Geometry::Cylinder {
+                half_height,
+                radius,
+            } => ShapeHandle::new(Cylinder::new(*half_height, *radius)),
+            // Only called once, from `attach_to_body` at registration time, so
+            // cloning the mesh buffers here is fine; revisit if this ever ends
+            // up on a per-step rebuild path.
+            Geometry::Mesh { points, indices } => {
+                ShapeHandle::new(TriMesh::new(points.clone(), indices.clone(), None))
+            }
+        }
+    }
+}
+
+/// The `PhysicsCollider` `Component` represents a `PhysicsWorld` `Collider` in
+/// Specs and contains all the data required for its creation. It lives
+/// alongside a [`PhysicsBody`] on the same `Entity`; the body-registration
+/// system calls [`PhysicsCollider::attach_to_body`] to attach the collider it
+/// describes to the `RigidBody` that was created for that `PhysicsBody`.
+#[derive(Clone, Debug)]
+pub struct PhysicsCollider<N: RealField> {
+    pub(crate) handle: Option<DefaultColliderHandle>,
+    pub geometry: Geometry<N>,
+    pub density: N,
+    pub margin: N,
+    pub collision_groups: CollisionGroups,
+    pub sensor: bool,
+}
+
+impl<N: RealField> Component for PhysicsCollider<N> {
+    type Storage = FlaggedStorage<Self, DenseVecStorage<Self>>;
+}
+
+impl<N: RealField> PhysicsCollider<N> {
+    /// For creating a new `ColliderDesc` from this component's values; the
+    /// collider is attached to a body by [`PhysicsCollider::attach_to_body`].
+    pub(crate) fn to_collider_desc(&self) -> ColliderDesc<N> {
+        ColliderDesc::new(self.geometry.to_shape_handle())
+            .density(self.density)
+            .margin(self.margin)
+            .collision_groups(self.collision_groups)
+            .sensor(self.sensor)
+    }
+
+    /// Builds this `PhysicsCollider`'s `Collider` and attaches it to part `0`
+    /// of `parent`, inserting it into `colliders` and storing the resulting
+    /// handle on this component. This is what the body-registration system
+    /// calls once the owning `PhysicsBody`'s `RigidBody` has been created, so
+    /// the collider always ends up attached to the right body.
+    pub(crate) fn attach_to_body(
+        &mut self,
+        parent: DefaultBodyHandle,
+        colliders: &mut DefaultColliderSet<N>,
+    ) -> DefaultColliderHandle {
+        let collider = self.to_collider_desc().build(BodyPartHandle(parent, 0));
+        let handle = colliders.insert(collider);
+        self.handle = Some(handle);
+        handle
+    }
+}
+
+/// The `PhysicsColliderBuilder` implements the builder pattern for
+/// `PhysicsCollider`s and is the recommended way of instantiating and
+/// customising new `PhysicsCollider` instances.
+///
+/// # Example
+///
+/// ```rust
+/// use specs_physics::{nalgebra::Vector3, Geometry, PhysicsColliderBuilder};
+///
+/// let physics_collider = PhysicsColliderBuilder::from(Geometry::Box {
+///     half_extents: Vector3::new(1.0, 1.0, 1.0),
+/// })
+/// .density(1.2)
+/// .margin(0.01)
+/// .sensor(false)
+/// .build();
+/// ```
+pub struct PhysicsColliderBuilder<N: RealField> {
+    geometry: Geometry<N>,
+    density: N,
+    margin: N,
+    collision_groups: CollisionGroups,
+    sensor: bool,
+}
+
+impl<N: RealField> From<Geometry<N>> for PhysicsColliderBuilder<N> {
+    /// Creates a new `PhysicsColliderBuilder` from the given `Geometry`. This
+    /// also populates the `PhysicsCollider` with sane defaults.
+    fn from(geometry: Geometry<N>) -> Self {
+        Self {
+            geometry,
+            density: N::from_f32(1.3).unwrap(),
+            margin: N::from_f32(0.01).unwrap(),
+            collision_groups: CollisionGroups::new(),
+            sensor: false,
+        }
+    }
+}
+
+impl<N: RealField> PhysicsColliderBuilder<N> {
+    /// Sets the `density` value of the `PhysicsColliderBuilder`.
+    pub fn density(mut self, density: N) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// Sets the `margin` value of the `PhysicsColliderBuilder`.
+    pub fn margin(mut self, margin: N) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Sets the `collision_groups` value of the `PhysicsColliderBuilder`.
+    pub fn collision_groups(mut self, collision_groups: CollisionGroups) -> Self {
+        self.collision_groups = collision_groups;
+        self
+    }
+
+    /// Sets the `sensor` value of the `PhysicsColliderBuilder`.
+    pub fn sensor(mut self, sensor: bool) -> Self {
+        self.sensor = sensor;
+        self
+    }
+
+    /// Builds the `PhysicsCollider` from the values set in the
+    /// `PhysicsColliderBuilder` instance.
+    pub fn build(self) -> PhysicsCollider<N> {
+        PhysicsCollider {
+            handle: None,
+            geometry: self.geometry,
+            density: self.density,
+            margin: self.margin,
+            collision_groups: self.collision_groups,
+            sensor: self.sensor,
         }
     }
 }